@@ -0,0 +1,45 @@
+use tauri::AppHandle;
+use tauri_plugin_autostart::ManagerExt;
+
+fn is_enabled(app: &AppHandle) -> bool {
+    app.autolaunch().is_enabled().unwrap_or(false)
+}
+
+fn sync_tray_label(app: &AppHandle) {
+    let label = if is_enabled(app) {
+        "Disable Autostart"
+    } else {
+        "Enable Autostart"
+    };
+    let _ = app.tray_handle().get_item("autostart").set_title(label);
+}
+
+fn set_enabled(app: &AppHandle, enabled: bool) {
+    let autolaunch = app.autolaunch();
+    let _ = if enabled {
+        autolaunch.enable()
+    } else {
+        autolaunch.disable()
+    };
+    sync_tray_label(app);
+}
+
+/// Syncs the tray label with the platform's actual autostart registration on launch.
+pub fn init(app: &AppHandle) {
+    sync_tray_label(app);
+}
+
+pub fn handle_tray_menu_item(app: &AppHandle, id: &str) -> bool {
+    match id {
+        "autostart" => {
+            set_enabled(app, !is_enabled(app));
+            true
+        }
+        _ => false,
+    }
+}
+
+#[tauri::command]
+pub fn set_autostart(app: AppHandle, enabled: bool) {
+    set_enabled(&app, enabled);
+}
@@ -4,32 +4,63 @@ use tauri::{
 };
 use tauri::Manager;
 
+mod autostart;
+mod stats;
+mod timer;
+
 fn handle_window_event(event: GlobalWindowEvent) {
     match event.event() {
         WindowEvent::CloseRequested { api, .. } => {
             api.prevent_close();
             event.window().hide().unwrap();
+            let app = event.window().app_handle();
+            app.tray_handle()
+                .get_item("toggle")
+                .set_title("Show")
+                .unwrap();
         }
         _ => {}
     }
 }
 
+fn toggle_main_window(app: &AppHandle) {
+    let window = app.get_window("main").unwrap();
+    let title = if window.is_visible().unwrap() {
+        window.hide().unwrap();
+        "Show"
+    } else {
+        window.show().unwrap();
+        window.set_focus().unwrap();
+        "Hide"
+    };
+    app.tray_handle().get_item("toggle").set_title(title).unwrap();
+}
+
+/// Unconditionally shows and focuses the window, unlike `toggle_main_window` — used for
+/// tray click events, where a double-click can deliver `LeftClick`/`DoubleClick` together
+/// and a toggle would flip visibility back off.
+fn restore_main_window(app: &AppHandle) {
+    let window = app.get_window("main").unwrap();
+    window.show().unwrap();
+    window.set_focus().unwrap();
+    app.tray_handle().get_item("toggle").set_title("Hide").unwrap();
+}
+
 fn handle_system_tray_event(app: &AppHandle, event: SystemTrayEvent) {
     match event {
-        SystemTrayEvent::MenuItemClick { id, .. } => {
-            let window = app.get_window("main").unwrap();
-            match id.as_str() {
-                "quit" => {
-                    app.exit(0);
-                }
-                "hide" => {
-                    window.hide().unwrap();
-                }
-                "show" => {
-                    window.show().unwrap();
+        SystemTrayEvent::MenuItemClick { id, .. } => match id.as_str() {
+            "quit" => {
+                app.exit(0);
+            }
+            "toggle" => toggle_main_window(app),
+            id => {
+                if !timer::handle_tray_menu_item(app, id) {
+                    autostart::handle_tray_menu_item(app, id);
                 }
-                _ => {}
             }
+        },
+        SystemTrayEvent::LeftClick { .. } | SystemTrayEvent::DoubleClick { .. } => {
+            restore_main_window(app);
         }
         _ => {}
     }
@@ -38,22 +69,51 @@ fn handle_system_tray_event(app: &AppHandle, event: SystemTrayEvent) {
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let quit = CustomMenuItem::new("quit".to_string(), "Quit");
-    let hide = CustomMenuItem::new("hide".to_string(), "Hide");
-    let show = CustomMenuItem::new("show".to_string(), "Show");
+    let toggle = CustomMenuItem::new("toggle".to_string(), "Hide");
+    let timer_title = CustomMenuItem::new("timer_title".to_string(), "Focus - 25:00").disabled();
+    let start_pause = CustomMenuItem::new("start_pause".to_string(), "Start");
+    let skip = CustomMenuItem::new("skip".to_string(), "Skip");
+    let autostart_toggle = CustomMenuItem::new("autostart".to_string(), "Enable Autostart");
     let tray_menu = SystemTrayMenu::new()
-        .add_item(show)
-        .add_item(hide)
+        .add_item(timer_title)
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(start_pause)
+        .add_item(skip)
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(toggle)
+        .add_item(autostart_toggle)
         .add_native_item(SystemTrayMenuItem::Separator)
         .add_item(quit);
 
     let system_tray = SystemTray::new().with_menu(tray_menu);
 
     tauri::Builder::default()
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            let window = app.get_window("main").unwrap();
+            window.unminimize().unwrap();
+            window.show().unwrap();
+            window.set_focus().unwrap();
+            app.tray_handle().get_item("toggle").set_title("Hide").unwrap();
+        }))
         .plugin(tauri_plugin_store::Builder::new().build())
         .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_autostart::init(
+            tauri_plugin_autostart::MacosLauncher::LaunchAgent,
+            None,
+        ))
         .system_tray(system_tray)
         .on_system_tray_event(handle_system_tray_event)
         .on_window_event(handle_window_event)
+        .manage(timer::TimerState::default())
+        .invoke_handler(tauri::generate_handler![
+            timer::start_timer,
+            timer::pause_timer,
+            timer::skip_timer,
+            stats::record_session,
+            stats::get_stats,
+            stats::reset_stats,
+            autostart::set_autostart,
+        ])
         .setup(|app| {
             if cfg!(debug_assertions) {
                 app.handle().plugin(
@@ -62,6 +122,9 @@ pub fn run() {
                         .build(),
                 )?;
             }
+            timer::spawn_ticker(app.handle());
+            stats::init(&app.handle());
+            autostart::init(&app.handle());
             Ok(())
         })
         .run(tauri::generate_context!())
@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::{with_store, StoreCollection};
+
+const STORE_PATH: &str = "stats.json";
+const STATS_KEY: &str = "stats";
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SessionRecord {
+    pub phase: String,
+    pub duration_secs: u64,
+    pub completed: bool,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Stats {
+    pub sessions: Vec<SessionRecord>,
+    pub completed_count: u32,
+    pub interrupted_count: u32,
+}
+
+fn store_path() -> PathBuf {
+    PathBuf::from(STORE_PATH)
+}
+
+fn load_stats(app: &AppHandle) -> Stats {
+    let stores = app.state::<StoreCollection>();
+    with_store(app.clone(), stores, store_path(), |store| {
+        Ok(store
+            .get(STATS_KEY)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default())
+    })
+    .unwrap_or_default()
+}
+
+fn save_stats(app: &AppHandle, stats: &Stats) {
+    let stores = app.state::<StoreCollection>();
+    let _ = with_store(app.clone(), stores, store_path(), |store| {
+        store.insert(STATS_KEY.to_string(), json!(stats))?;
+        store.save()
+    });
+}
+
+/// Warms the on-disk store so the first IPC call doesn't pay the read cost.
+pub fn init(app: &AppHandle) {
+    load_stats(app);
+}
+
+#[tauri::command]
+pub fn record_session(app: AppHandle, phase: String, duration_secs: u64, completed: bool, timestamp: i64) {
+    let mut stats = load_stats(&app);
+    if completed {
+        stats.completed_count += 1;
+    } else {
+        stats.interrupted_count += 1;
+    }
+    stats.sessions.push(SessionRecord {
+        phase,
+        duration_secs,
+        completed,
+        timestamp,
+    });
+    save_stats(&app, &stats);
+}
+
+#[tauri::command]
+pub fn get_stats(app: AppHandle) -> Stats {
+    load_stats(&app)
+}
+
+#[tauri::command]
+pub fn reset_stats(app: AppHandle) {
+    save_stats(&app, &Stats::default());
+}
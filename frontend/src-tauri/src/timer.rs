@@ -0,0 +1,188 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+const FOCUS_SECS: u64 = 25 * 60;
+const SHORT_BREAK_SECS: u64 = 5 * 60;
+const LONG_BREAK_SECS: u64 = 15 * 60;
+const SESSIONS_BEFORE_LONG_BREAK: u32 = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Focus,
+    ShortBreak,
+    LongBreak,
+}
+
+impl Phase {
+    fn label(self) -> &'static str {
+        match self {
+            Phase::Focus => "Focus",
+            Phase::ShortBreak => "Short Break",
+            Phase::LongBreak => "Long Break",
+        }
+    }
+
+    fn duration_secs(self) -> u64 {
+        match self {
+            Phase::Focus => FOCUS_SECS,
+            Phase::ShortBreak => SHORT_BREAK_SECS,
+            Phase::LongBreak => LONG_BREAK_SECS,
+        }
+    }
+
+    fn next(self, completed_focus_sessions: u32) -> Phase {
+        match self {
+            Phase::Focus => {
+                if completed_focus_sessions % SESSIONS_BEFORE_LONG_BREAK == 0 {
+                    Phase::LongBreak
+                } else {
+                    Phase::ShortBreak
+                }
+            }
+            Phase::ShortBreak | Phase::LongBreak => Phase::Focus,
+        }
+    }
+}
+
+struct TimerInner {
+    phase: Phase,
+    remaining: u64,
+    running: bool,
+    completed_focus_sessions: u32,
+}
+
+impl Default for TimerInner {
+    fn default() -> Self {
+        Self {
+            phase: Phase::Focus,
+            remaining: Phase::Focus.duration_secs(),
+            running: false,
+            completed_focus_sessions: 0,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TimerState(Mutex<TimerInner>);
+
+#[derive(Clone, Serialize)]
+pub struct TimerTick {
+    pub phase: &'static str,
+    pub remaining: u64,
+    pub running: bool,
+}
+
+fn format_tray_title(tick: &TimerTick) -> String {
+    format!("{} - {:02}:{:02}", tick.phase, tick.remaining / 60, tick.remaining % 60)
+}
+
+fn emit_tick(app: &AppHandle, state: &TimerState) {
+    let tick = {
+        let inner = state.0.lock().unwrap();
+        TimerTick {
+            phase: inner.phase.label(),
+            remaining: inner.remaining,
+            running: inner.running,
+        }
+    };
+
+    let _ = app.emit_all("timer-tick", tick.clone());
+
+    let tray = app.tray_handle();
+    let _ = tray.get_item("timer_title").set_title(&format_tray_title(&tick));
+    let _ = tray
+        .get_item("start_pause")
+        .set_title(if tick.running { "Pause" } else { "Start" });
+}
+
+fn start(app: &AppHandle) {
+    let state = app.state::<TimerState>();
+    state.0.lock().unwrap().running = true;
+    emit_tick(app, &state);
+}
+
+fn pause(app: &AppHandle) {
+    let state = app.state::<TimerState>();
+    state.0.lock().unwrap().running = false;
+    emit_tick(app, &state);
+}
+
+fn skip(app: &AppHandle) {
+    let state = app.state::<TimerState>();
+    {
+        let mut inner = state.0.lock().unwrap();
+        if inner.phase == Phase::Focus {
+            inner.completed_focus_sessions += 1;
+        }
+        inner.phase = inner.phase.next(inner.completed_focus_sessions);
+        inner.remaining = inner.phase.duration_secs();
+    }
+    emit_tick(app, &state);
+}
+
+pub fn handle_tray_menu_item(app: &AppHandle, id: &str) -> bool {
+    match id {
+        "start_pause" => {
+            let running = app.state::<TimerState>().0.lock().unwrap().running;
+            if running {
+                pause(app);
+            } else {
+                start(app);
+            }
+            true
+        }
+        "skip" => {
+            skip(app);
+            true
+        }
+        _ => false,
+    }
+}
+
+pub fn spawn_ticker(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(1));
+        loop {
+            interval.tick().await;
+            let state = app.state::<TimerState>();
+            let running = {
+                let mut inner = state.0.lock().unwrap();
+                if !inner.running {
+                    false
+                } else {
+                    if inner.remaining > 0 {
+                        inner.remaining -= 1;
+                    } else {
+                        if inner.phase == Phase::Focus {
+                            inner.completed_focus_sessions += 1;
+                        }
+                        inner.phase = inner.phase.next(inner.completed_focus_sessions);
+                        inner.remaining = inner.phase.duration_secs();
+                    }
+                    true
+                }
+            };
+            if running {
+                emit_tick(&app, &state);
+            }
+        }
+    });
+}
+
+#[tauri::command]
+pub fn start_timer(app: AppHandle) {
+    start(&app);
+}
+
+#[tauri::command]
+pub fn pause_timer(app: AppHandle) {
+    pause(&app);
+}
+
+#[tauri::command]
+pub fn skip_timer(app: AppHandle) {
+    skip(&app);
+}